@@ -104,6 +104,7 @@ async fn main() {
 
         // drag nodes with the mouse
         if is_mouse_button_down(MouseButton::Left) {
+            let mut newly_dragged = None;
             graph.visit_nodes_mut(|_, node| {
                 if let Some(idx) = dragging_node_idx {
                     if idx == node.index() {
@@ -113,10 +114,14 @@ async fn main() {
                     }
                 } else if node_overlaps_mouse_position(node) {
                     dragging_node_idx = Some(node.index());
+                    newly_dragged = Some(node.index());
                 }
             });
-        } else {
-            dragging_node_idx = None;
+            if let Some(idx) = newly_dragged {
+                graph.set_anchor(idx, true);
+            }
+        } else if let Some(idx) = dragging_node_idx.take() {
+            graph.set_anchor(idx, false);
         }
 
         if i <= 100 {
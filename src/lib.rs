@@ -1,7 +1,9 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::ops::{DerefMut, Deref, MulAssign};
 pub use naive_graph::*;
 use std::fmt::{Debug, Result as FmtRs, Formatter};
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
 
 #[derive(Debug)]
 pub struct Node<NodeUserData> {
@@ -55,10 +57,17 @@ impl<NodeUserData> Node<NodeUserData> {
     pub fn y(&self) -> f32 {
         self.data.y
     }
-    fn apply(&mut self, force: Vec2) {
-        if force.x.is_nan() {
+    fn apply(&mut self, force: Vec2, max_force: f32) {
+        if force.x.is_nan() || force.y.is_nan() {
             panic!("force.x nan")
         }
+        let magnitude = (force.x.powi(2) + force.y.powi(2)).sqrt();
+        let force = if magnitude > max_force && magnitude > f32::EPSILON {
+            let scale = max_force / magnitude;
+            Vec2 { x: force.x * scale, y: force.y * scale }
+        } else {
+            force
+        };
         self.ax += force.x;
         self.ay += force.y;
     }
@@ -72,11 +81,51 @@ impl<NodeUserData> Node<NodeUserData> {
         // }
 
         // println!("before {:?} {:?}", self.id.unwrap(), (self.data.x, self.data.y));
+
+        if self.data.fixed {
+            self.vx = 0.;
+            self.vy = 0.;
+            self.ax = 0.;
+            self.ay = 0.;
+            return;
+        }
+
+        // semi-implicit (velocity-Verlet-style) integration: accelerate from the
+        // accumulated force, damp to bleed off kinetic energy, then advance position.
+        let mass = self.data.mass.max(f32::EPSILON);
+        let ax = self.ax / mass;
+        let ay = self.ay / mass;
+
+        self.vx = (self.vx + ax * dt) * parameters.damping;
+        self.vy = (self.vy + ay * dt) * parameters.damping;
+
+        let speed = (self.vx.powi(2) + self.vy.powi(2)).sqrt();
+        if speed > parameters.max_speed {
+            let scale = parameters.max_speed / speed;
+            self.vx *= scale;
+            self.vy *= scale;
+        }
+
         self.data.x += parameters.spring_factor * self.vx * dt;
         self.data.y += parameters.spring_factor * self.vy * dt;
-        
-        self.vx = self.ax * dt;
-        self.vy = self.ay * dt;
+
+        if let Some((min, max)) = &parameters.bounds {
+            if self.data.x < min.x {
+                self.data.x = min.x;
+                self.vx = -self.vx * parameters.restitution;
+            } else if self.data.x > max.x {
+                self.data.x = max.x;
+                self.vx = -self.vx * parameters.restitution;
+            }
+            if self.data.y < min.y {
+                self.data.y = min.y;
+                self.vy = -self.vy * parameters.restitution;
+            } else if self.data.y > max.y {
+                self.data.y = max.y;
+                self.vy = -self.vy * parameters.restitution;
+            }
+        }
+
         self.ax = 0.;
         self.ay = 0.;
     }
@@ -101,11 +150,25 @@ impl<NodeUserData> DerefMut for Node<NodeUserData> {
     }
 }
 
-#[derive(Default, Debug)]
+#[derive(Debug)]
 pub struct NodeData<NodeUserData> {
     pub user_data: NodeUserData,
     pub x: f32,
     pub y: f32,
+    pub mass: f32,
+    pub fixed: bool,
+}
+
+impl<NodeUserData: Default> Default for NodeData<NodeUserData> {
+    fn default() -> Self {
+        Self {
+            user_data: NodeUserData::default(),
+            x: 0.,
+            y: 0.,
+            mass: 1.,
+            fixed: false,
+        }
+    }
 }
 
 pub struct Parameters {
@@ -115,6 +178,14 @@ pub struct Parameters {
     pub escape_intersection_factor: f32,
     pub distance_factor: f32,
     pub count: i32,
+    pub damping: f32,
+    pub max_force: f32,
+    pub max_speed: f32,
+    /// `0.` disables the quadtree and falls back to the exact O(n^2) repulsion loop.
+    pub theta: f32,
+    pub bounds: Option<(Vec2, Vec2)>,
+    pub restitution: f32,
+    pub seed: u64,
 }
 
 impl Default for Parameters {
@@ -126,14 +197,149 @@ impl Default for Parameters {
             escape_intersection_factor: 100.,
             distance_factor: 0.3,
             count: 0,
+            damping: 0.9,
+            max_force: 10000.,
+            max_speed: 1000.,
+            theta: 0.5,
+            bounds: None,
+            restitution: 0.5,
+            seed: 0,
+        }
+    }
+}
+
+struct QuadNode {
+    cx: f32,
+    cy: f32,
+    half: f32,
+    mass: f32,
+    com_x: f32,
+    com_y: f32,
+    content: QuadContent,
+}
+
+enum QuadContent {
+    Empty,
+    /// Cells at or below `min_size` stop subdividing, so near-coincident nodes can't recurse forever.
+    Leaf(Vec<(NodeId, f32, f32, f32)>),
+    Internal(Box<[QuadNode; 4]>),
+}
+
+fn quadrant_index(cx: f32, cy: f32, x: f32, y: f32) -> usize {
+    match (x >= cx, y >= cy) {
+        (false, false) => 0,
+        (true, false) => 1,
+        (false, true) => 2,
+        (true, true) => 3,
+    }
+}
+
+impl QuadNode {
+    fn empty(cx: f32, cy: f32, half: f32) -> Self {
+        QuadNode { cx, cy, half, mass: 0., com_x: cx, com_y: cy, content: QuadContent::Empty }
+    }
+    fn insert(&mut self, id: NodeId, x: f32, y: f32, mass: f32, min_size: f32) {
+        if self.mass <= 0. {
+            self.content = QuadContent::Leaf(vec![(id, x, y, mass)]);
+            self.mass = mass;
+            self.com_x = x;
+            self.com_y = y;
+            return;
+        }
+
+        let total_mass = self.mass + mass;
+        self.com_x = (self.com_x * self.mass + x * mass) / total_mass;
+        self.com_y = (self.com_y * self.mass + y * mass) / total_mass;
+        self.mass = total_mass;
+
+        if self.half <= min_size {
+            if let QuadContent::Leaf(bodies) = &mut self.content {
+                bodies.push((id, x, y, mass));
+                return;
+            }
+        }
+
+        if let QuadContent::Leaf(bodies) = &mut self.content {
+            let existing = std::mem::take(bodies);
+            let half = self.half / 2.;
+            let mut children = [
+                QuadNode::empty(self.cx - half, self.cy - half, half),
+                QuadNode::empty(self.cx + half, self.cy - half, half),
+                QuadNode::empty(self.cx - half, self.cy + half, half),
+                QuadNode::empty(self.cx + half, self.cy + half, half),
+            ];
+            for (eid, ex, ey, emass) in existing {
+                let q = quadrant_index(self.cx, self.cy, ex, ey);
+                children[q].insert(eid, ex, ey, emass, min_size);
+            }
+            self.content = QuadContent::Internal(Box::new(children));
+        }
+
+        if let QuadContent::Internal(children) = &mut self.content {
+            let q = quadrant_index(self.cx, self.cy, x, y);
+            children[q].insert(id, x, y, mass, min_size);
         }
     }
+    fn accumulate_force<NodeUserData, EdgeUserData>(
+        &self,
+        ctx: &QuadTraversal<NodeUserData, EdgeUserData>,
+        exclude: NodeId,
+        mx: f32,
+        my: f32,
+        contributions: &mut Vec<Vec2>,
+        found_close: &mut bool,
+    ) {
+        if self.mass <= 0. {
+            return;
+        }
+        match &self.content {
+            QuadContent::Empty => {}
+            QuadContent::Leaf(bodies) => {
+                for &(id, x, y, _mass) in bodies {
+                    if id == exclude {
+                        continue;
+                    }
+                    let diff = Vec2 { x: mx - x, y: my - y };
+                    let distance = (diff.x.powi(2) + diff.y.powi(2)).sqrt();
+                    if distance < ctx.really_close_distance {
+                        *found_close = true;
+                    }
+                    // is_neighbor is forced false: the far-range spring term is
+                    // applied once per edge by the caller, so picking it up here
+                    // too would double it for any neighbor resolved as its own leaf.
+                    contributions.push(ctx.graph.calculate_force(diff, distance, false));
+                }
+            }
+            QuadContent::Internal(children) => {
+                let dx = mx - self.com_x;
+                let dy = my - self.com_y;
+                let d = (dx.powi(2) + dy.powi(2)).sqrt();
+                let s = self.half * 2.;
+
+                if s / d.max(ctx.really_close_distance) < ctx.theta {
+                    let f = ctx.graph.calculate_force(Vec2 { x: dx, y: dy }, d, false);
+                    contributions.push(Vec2 { x: f.x * self.mass, y: f.y * self.mass });
+                } else {
+                    for child in children.iter() {
+                        child.accumulate_force(ctx, exclude, mx, my, contributions, found_close);
+                    }
+                }
+            }
+        }
+    }
+}
+
+struct QuadTraversal<'a, NodeUserData, EdgeUserData> {
+    graph: &'a ForceGraph<NodeUserData, EdgeUserData>,
+    theta: f32,
+    really_close_distance: f32,
 }
 
 pub struct ForceGraph<NodeUserData = (), EdgeUserData = ()> {
     graph: Graph<Node<NodeUserData>, EdgeUserData>,
     parameters: Parameters,
     nodes: HashSet<NodeId>,
+    rng: StdRng,
 }
 
 impl<NodeUserData, EdgeUserData> Debug for ForceGraph<NodeUserData, EdgeUserData> {
@@ -144,10 +350,12 @@ impl<NodeUserData, EdgeUserData> Debug for ForceGraph<NodeUserData, EdgeUserData
 
 impl<NodeUserData, EdgeUserData> ForceGraph<NodeUserData, EdgeUserData> {
     pub fn new(parameters: Parameters) -> Self {
+        let rng = StdRng::seed_from_u64(parameters.seed);
         Self {
             graph: Graph::default(),
             parameters,
             nodes: HashSet::new(),
+            rng,
         }
     }
     pub fn node_count(&self) -> usize {
@@ -169,6 +377,9 @@ impl<NodeUserData, EdgeUserData> ForceGraph<NodeUserData, EdgeUserData> {
     pub fn add_edge(&mut self, node1: NodeId, node2: NodeId, data: EdgeUserData) -> EdgeId {
         self.graph.add_edge(node1, node2, data)
     }
+    pub fn set_anchor(&mut self, id: NodeId, fixed: bool) {
+        self.graph[id].data.fixed = fixed;
+    }
     pub fn visit_edges<F: FnMut(EdgeId, &Node<NodeUserData>, &Node<NodeUserData>, &EdgeUserData)>(&self, f: F) {
         self.graph.visit_edges(f)
     }
@@ -178,6 +389,64 @@ impl<NodeUserData, EdgeUserData> ForceGraph<NodeUserData, EdgeUserData> {
     pub fn visit_nodes_mut<F: FnMut(NodeId, &mut Node<NodeUserData>)>(&mut self, f: F) {
         self.graph.visit_nodes_mut(f)
     }
+    pub fn from_adjacency_matrix(s: &str, params: Parameters) -> (Self, Vec<NodeId>)
+    where
+        NodeUserData: Default,
+        EdgeUserData: Default,
+    {
+        let rows: Vec<Vec<u8>> = s.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.split_whitespace().map(|tok| tok.parse::<u8>().unwrap_or(0)).collect())
+            .collect();
+
+        let n = rows.len();
+        let mut graph = Self::new(params);
+        let mut ids = Vec::with_capacity(n);
+
+        let radius = graph.parameters.ideal_distance * n as f32 / (2. * std::f32::consts::PI);
+        for i in 0..n {
+            let angle = 2. * std::f32::consts::PI * i as f32 / n as f32;
+            let id = graph.add_node(NodeData {
+                x: radius * angle.cos(),
+                y: radius * angle.sin(),
+                ..NodeData::default()
+            });
+            ids.push(id);
+        }
+
+        let mut seen = HashSet::new();
+        for (i, row) in rows.iter().enumerate() {
+            for (j, &value) in row.iter().enumerate() {
+                if value == 0 || i == j || j >= ids.len() {
+                    continue;
+                }
+                let key = if i < j { (i, j) } else { (j, i) };
+                if !seen.insert(key) {
+                    continue;
+                }
+                graph.add_edge(ids[i], ids[j], EdgeUserData::default());
+            }
+        }
+
+        (graph, ids)
+    }
+    pub fn to_adjacency_matrix(&self, order: &[NodeId]) -> String {
+        let index_of: HashMap<NodeId, usize> = order.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+        let n = order.len();
+        let mut matrix = vec![vec![0u8; n]; n];
+
+        self.graph.visit_edges(|_, n1, n2, _| {
+            if let (Some(&i), Some(&j)) = (index_of.get(&n1.index()), index_of.get(&n2.index())) {
+                matrix[i][j] = 1;
+                matrix[j][i] = 1;
+            }
+        });
+
+        matrix.into_iter()
+            .map(|row| row.iter().map(u8::to_string).collect::<Vec<_>>().join(" "))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
     fn calculate_force(&self, diff: Vec2, distance: f32, is_neighbor: bool) -> Vec2 {
 
         if distance <= f32::EPSILON {
@@ -214,11 +483,63 @@ impl<NodeUserData, EdgeUserData> ForceGraph<NodeUserData, EdgeUserData> {
             };
         }
 
-        Vec2 { x: 0., y: 0. }        
+        Vec2 { x: 0., y: 0. }
+    }
+    fn calculate_attraction(&self, diff: Vec2, distance: f32) -> Vec2 {
+        if distance <= f32::EPSILON {
+            return Vec2 { x: 0., y: 0. };
+        }
+        if distance > self.parameters.ideal_distance * 1.5 {
+            let factor = self.parameters.distance_factor;
+            let f_x = -diff.x / distance * distance.powf(factor);
+            let f_y = -diff.y / distance * distance.powf(factor);
+
+            return Vec2 {
+                x: f_x,
+                y: f_y,
+            };
+        }
+        Vec2 { x: 0., y: 0. }
+    }
+    fn sorted_node_ids(&self) -> Vec<NodeId> {
+        let mut ids: Vec<NodeId> = self.nodes.iter().copied().collect();
+        ids.sort();
+        ids
+    }
+    fn build_quadtree(&self, node_order: &[NodeId]) -> QuadNode {
+        let mut min_x = f32::INFINITY;
+        let mut min_y = f32::INFINITY;
+        let mut max_x = f32::NEG_INFINITY;
+        let mut max_y = f32::NEG_INFINITY;
+        for &id in node_order {
+            let data = &self.graph[id].data;
+            min_x = min_x.min(data.x);
+            min_y = min_y.min(data.y);
+            max_x = max_x.max(data.x);
+            max_y = max_y.max(data.y);
+        }
+        if !min_x.is_finite() {
+            min_x = 0.;
+            min_y = 0.;
+            max_x = 0.;
+            max_y = 0.;
+        }
+
+        let cx = (min_x + max_x) / 2.;
+        let cy = (min_y + max_y) / 2.;
+        let half = ((max_x - min_x).max(max_y - min_y) / 2.).max(self.parameters.really_close_distance);
+        let min_size = self.parameters.really_close_distance;
+
+        let mut root = QuadNode::empty(cx, cy, half);
+        for &id in node_order {
+            let data = &self.graph[id].data;
+            root.insert(id, data.x, data.y, data.mass, min_size);
+        }
+        root
     }
     pub fn update(&mut self, dt: f32) {
-        fn bounce(really_close_distance: f32) -> Vec2 {            
-            let x = rand::random::<f32>() * really_close_distance;
+        fn bounce(rng: &mut StdRng, really_close_distance: f32) -> Vec2 {
+            let x = rng.gen::<f32>() * really_close_distance;
             let y = (1. - x.powi(2)).sqrt() * really_close_distance;
             Vec2 { x, y }
         }
@@ -226,16 +547,21 @@ impl<NodeUserData, EdgeUserData> ForceGraph<NodeUserData, EdgeUserData> {
             self.parameters.count += 1;
         }
         let really_close_distance = self.parameters.really_close_distance;
+        let theta = self.parameters.theta;
+        let node_order = self.sorted_node_ids();
+        let tree = if theta > 0. { Some(self.build_quadtree(&node_order)) } else { None };
         let mut bouncing = None;
         'loop_nodes:
-        for &m in &self.nodes {
+        for &m in &node_order {
             bouncing = None;
             let m_neighbors = self.graph.neighbor_id_set(m);
+            let mut m_neighbor_order: Vec<NodeId> = m_neighbors.iter().copied().collect();
+            m_neighbor_order.sort();
             if self.parameters.count < 100 {
                 //println!("neighbors {:?}", m_neighbors.len())
             }
             let mut force = Vec2 { x: 0., y: 0. };
-            if self.graph[m].is_stable() {
+            if self.graph[m].is_stable() && !self.graph[m].data.fixed {
                 // println!("!stable {:?} dt {}", m, dt);
                 self.visit_neighbor_intersections(m, |info| {
                     if bouncing.is_some() {
@@ -265,38 +591,70 @@ impl<NodeUserData, EdgeUserData> ForceGraph<NodeUserData, EdgeUserData> {
                     bouncing = Some((m, vector));
                 });
                 if bouncing.is_some() {
-                    self.graph[m].apply(force);
+                    self.graph[m].apply(force, self.parameters.max_force);
                     self.graph[m].update(&self.parameters, dt);
                     break 'loop_nodes;
                 }
             }
             
-            for &n in &self.nodes {
-                if m == n { continue }
-                let dst = &self.graph[m].data;
-                let src = &self.graph[n].data;
-                let diff = Vec2 { x: dst.x - src.x, y: dst.y - src.y, };
-                let distance = (diff.x.powi(2) + diff.y.powi(2)).sqrt();
-
-                if distance < really_close_distance && bouncing.is_none() {
-                    bouncing = Some((m, bounce(really_close_distance)));
+            if let Some(tree) = &tree {
+                let mx = self.graph[m].x();
+                let my = self.graph[m].y();
+                let mut contributions = Vec::new();
+                let mut found_close = false;
+                {
+                    let ctx = QuadTraversal { graph: self, theta, really_close_distance };
+                    tree.accumulate_force(&ctx, m, mx, my, &mut contributions, &mut found_close);
+                }
+
+                if found_close && !self.graph[m].data.fixed {
+                    bouncing = Some((m, bounce(&mut self.rng, really_close_distance)));
                     self.graph[m].update(&self.parameters, dt);
                     break 'loop_nodes;
                 }
 
-                let f = self.calculate_force(diff, distance, m_neighbors.contains(&n));
-                if self.parameters.count < 100 {
-                    //println!("calculate_force {:?}", f)
+                for f in contributions {
+                    self.graph[m].apply(f, self.parameters.max_force);
+                }
+
+                for &n in &m_neighbor_order {
+                    let dst = &self.graph[m].data;
+                    let src = &self.graph[n].data;
+                    let diff = Vec2 { x: dst.x - src.x, y: dst.y - src.y, };
+                    let distance = (diff.x.powi(2) + diff.y.powi(2)).sqrt();
+                    let attraction = self.calculate_attraction(diff, distance);
+                    self.graph[m].apply(attraction, self.parameters.max_force);
+                }
+            } else {
+                for &n in &node_order {
+                    if m == n { continue }
+                    let dst = &self.graph[m].data;
+                    let src = &self.graph[n].data;
+                    let diff = Vec2 { x: dst.x - src.x, y: dst.y - src.y, };
+                    let distance = (diff.x.powi(2) + diff.y.powi(2)).sqrt();
+
+                    if distance < really_close_distance && bouncing.is_none() && !self.graph[m].data.fixed {
+                        bouncing = Some((m, bounce(&mut self.rng, really_close_distance)));
+                        self.graph[m].update(&self.parameters, dt);
+                        break 'loop_nodes;
+                    }
+
+                    let f = self.calculate_force(diff, distance, m_neighbors.contains(&n));
+                    if self.parameters.count < 100 {
+                        //println!("calculate_force {:?}", f)
+                    }
+                    self.graph[m].apply(f, self.parameters.max_force);
                 }
-                self.graph[m].apply(f);
             }
             self.graph[m].update(&self.parameters, dt);
         }
         if let Some((id, b)) = bouncing {
-            // println!("before {:?}", (self.graph[id].x, self.graph[id].y));
-            self.graph[id].x += b.x;
-            self.graph[id].y += b.y;
-            // println!("after {:?}", (self.graph[id].x, self.graph[id].y));
+            if !self.graph[id].data.fixed {
+                // println!("before {:?}", (self.graph[id].x, self.graph[id].y));
+                self.graph[id].x += b.x;
+                self.graph[id].y += b.y;
+                // println!("after {:?}", (self.graph[id].x, self.graph[id].y));
+            }
         }
     }
     pub fn visit_intersections<F: FnMut(IntersectionInfo)>(&self, mut f: F) {
@@ -396,3 +754,110 @@ impl IntersectionInfo {
         self.pair
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apply_clamps_force_magnitude() {
+        let mut node = Node::new(NodeData::<()>::default());
+        node.apply(Vec2 { x: 100_000., y: 0. }, 10.);
+        assert!((node.ax - 10.).abs() < 1e-3);
+        assert_eq!(node.ay, 0.);
+    }
+
+    #[test]
+    fn damping_bleeds_off_velocity_over_time() {
+        let params = Parameters::default();
+        let mut node = Node::new(NodeData::<()>::default());
+        node.apply(Vec2 { x: 100., y: 0. }, params.max_force);
+        node.update(&params, 0.016);
+        let v1 = (node.vx.powi(2) + node.vy.powi(2)).sqrt();
+        node.apply(Vec2 { x: 0., y: 0. }, params.max_force);
+        node.update(&params, 0.016);
+        let v2 = (node.vx.powi(2) + node.vy.powi(2)).sqrt();
+        assert!(v2 < v1);
+    }
+
+    #[test]
+    fn fixed_node_does_not_move() {
+        let mut g = ForceGraph::<(), ()>::new(Parameters::default());
+        let a = g.add_node(NodeData { x: 0., y: 0., ..NodeData::default() });
+        let b = g.add_node(NodeData { x: 10., y: 0., ..NodeData::default() });
+        g.add_edge(a, b, ());
+        g.set_anchor(a, true);
+        let (x0, y0) = (g.graph[a].x(), g.graph[a].y());
+
+        for _ in 0..10 {
+            g.update(0.016);
+        }
+
+        assert_eq!(g.graph[a].x(), x0);
+        assert_eq!(g.graph[a].y(), y0);
+    }
+
+    #[test]
+    fn quadtree_matches_exact_fallback_on_edge_attraction() {
+        let build = |theta: f32| {
+            let params = Parameters { theta, seed: 1, ..Parameters::default() };
+            let mut g = ForceGraph::<(), ()>::new(params);
+            let a = g.add_node(NodeData { x: 0., y: 0., ..NodeData::default() });
+            let b = g.add_node(NodeData { x: 200., y: 0., ..NodeData::default() });
+            let c = g.add_node(NodeData { x: 2000., y: 2000., ..NodeData::default() });
+            g.add_edge(a, b, ());
+            (g, a, b, c)
+        };
+
+        let (mut exact, a1, _, _) = build(0.);
+        let (mut approx, a2, _, _) = build(0.5);
+
+        exact.update(0.016);
+        approx.update(0.016);
+
+        assert_eq!(exact.graph[a1].x(), approx.graph[a2].x());
+        assert_eq!(exact.graph[a1].y(), approx.graph[a2].y());
+    }
+
+    #[test]
+    fn bounds_clamp_position_and_reflect_velocity() {
+        let mut params = Parameters::default();
+        params.bounds = Some((Vec2::new(0., 0.), Vec2::new(100., 100.)));
+        params.restitution = 0.5;
+
+        let mut node = Node::new(NodeData::<()> { x: 95., y: 50., ..NodeData::default() });
+        node.apply(Vec2 { x: 100_000., y: 0. }, params.max_force);
+        node.update(&params, 0.1);
+
+        assert_eq!(node.x(), 100.);
+        assert!(node.vx < 0.);
+    }
+
+    #[test]
+    fn adjacency_matrix_round_trips() {
+        let matrix = "0 1 0\n1 0 1\n0 1 0";
+        let (graph, ids) = ForceGraph::<(), ()>::from_adjacency_matrix(matrix, Parameters::default());
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.edge_count(), 2);
+        let exported = graph.to_adjacency_matrix(&ids);
+        assert_eq!(exported, "0 1 0\n1 0 1\n0 1 0");
+    }
+
+    #[test]
+    fn same_seed_converges_to_same_coordinates() {
+        let matrix = "0 1 1 0\n1 0 0 1\n1 0 0 1\n0 1 1 0";
+        let params = || Parameters { seed: 42, ..Parameters::default() };
+        let (mut a, ids_a) = ForceGraph::<(), ()>::from_adjacency_matrix(matrix, params());
+        let (mut b, ids_b) = ForceGraph::<(), ()>::from_adjacency_matrix(matrix, params());
+
+        for _ in 0..50 {
+            a.update(0.016);
+            b.update(0.016);
+        }
+
+        for (&id_a, &id_b) in ids_a.iter().zip(ids_b.iter()) {
+            assert_eq!(a.graph[id_a].x(), b.graph[id_b].x());
+            assert_eq!(a.graph[id_a].y(), b.graph[id_b].y());
+        }
+    }
+}